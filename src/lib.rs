@@ -35,35 +35,30 @@
 //! }
 //! ```
 //! 
-//! calling `arg_parser.help()` will generate the following:
-//! 
+//! calling `arg_parser.help()` will generate the following (assuming
+//! an 80-column terminal, the default when one can't be detected):
+//!
 //! ``` txt
 //! Test Parser, Tests arguments
-//! Usage: -h for help:
+//! Usage: Test Parser [-a <some>] [-b <some> <thing>] [-c <some>] [-d] foo bar
 //!
 //! Options:
-//!    -a <some> :
-//!	        This is the a flag
-//!    -b <some> <thing> :
-//!	        This is the b flag
-//!    -c <some> :
-//!     	 This is the c flag
-//!    -d :
-//!	        This is the d flag
+//!     -a <some>          This is the a flag
+//!     -b <some> <thing>  This is the b flag
+//!     -c <some>          This is the c flag
+//!     -d                 This is the d flag
 //!
 //! Arguments:
-//!    foo :
-//!     	 This is the foo argument
-//!    bar :
-//!     	 This is the bar argument
+//!     foo                This is the foo argument
+//!     bar                This is the bar argument
 //! ```
-//! 
+//!
 //! ## Modules
 //! Args: essential for parsing arguments.
-//! Process: used for ending the program early when an error occurs.
 
 use std::env::Args;
-use std::process;
+use std::error::Error;
+use std::fmt;
 
 /// A parser. This is responsible for the help function
 /// as well as handeling argument logic.
@@ -88,6 +83,12 @@ use std::process;
 ///         project_description: "Project Description",
 ///         flags: flags,
 ///         arguments: args,
+///         subcommands: Vec::new(),
+///         help_width: None,
+///         version: None,
+///         author: None,
+///         copyright: None,
+///         usage_example: None,
 ///     }
 /// }
 /// ```
@@ -96,6 +97,161 @@ pub struct Parser {
     project_description: String,
     flags: Vec<Flag>,
     arguments: Vec<Argument>,
+    subcommands: Vec<Parser>,
+    help_width: Option<usize>,
+    version: Option<String>,
+    author: Option<String>,
+    copyright: Option<String>,
+    usage_example: Option<String>,
+}
+
+/// Columns assumed for [`Parser::help`] wrapping when the terminal width
+/// can't be queried (not a TTY, or the platform has no way to ask).
+const DEFAULT_HELP_WIDTH: usize = 80;
+
+/// Detects the current terminal width, falling back to
+/// [`DEFAULT_HELP_WIDTH`] when output isn't a TTY or the width can't be
+/// queried.
+fn terminal_width() -> usize {
+    query_terminal_width().unwrap_or(DEFAULT_HELP_WIDTH)
+}
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn query_terminal_width() -> Option<usize> {
+    use std::io::IsTerminal;
+    use std::os::unix::io::AsRawFd;
+
+    #[cfg(target_os = "linux")]
+    const TIOCGWINSZ: u64 = 0x5413;
+    #[cfg(target_os = "macos")]
+    const TIOCGWINSZ: u64 = 0x40087468;
+
+    #[repr(C)]
+    struct Winsize {
+        ws_row: u16,
+        ws_col: u16,
+        ws_xpixel: u16,
+        ws_ypixel: u16,
+    }
+
+    extern "C" {
+        fn ioctl(fd: i32, request: u64, ...) -> i32;
+    }
+
+    let stdout = std::io::stdout();
+    if !stdout.is_terminal() {
+        return None;
+    }
+
+    let mut winsize = Winsize { ws_row: 0, ws_col: 0, ws_xpixel: 0, ws_ypixel: 0 };
+    let result = unsafe { ioctl(stdout.as_raw_fd(), TIOCGWINSZ, &mut winsize as *mut Winsize) };
+    if result == 0 && winsize.ws_col > 0 {
+        Some(winsize.ws_col as usize)
+    } else {
+        None
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn query_terminal_width() -> Option<usize> {
+    None
+}
+
+/// The display width of `s`, measured in terminal columns rather than
+/// bytes, so multibyte descriptions still line up.
+fn display_width(s: &str) -> usize {
+    s.chars().map(char_width).sum()
+}
+
+/// The terminal column width of a single character: `0` for control
+/// characters below U+0020, `2` for characters in the common East-Asian wide ranges,
+/// `1` otherwise.
+fn char_width(c: char) -> usize {
+    let cp = c as u32;
+    if cp < 0x20 {
+        return 0;
+    }
+    let is_wide = matches!(cp,
+        0x1100..=0x115F
+        | 0x2E80..=0xA4CF
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6
+        | 0x20000..=0x3FFFD
+    );
+    if is_wide { 2 } else { 1 }
+}
+
+/// Greedily packs `description`'s words into lines no wider than
+/// `width - indent`, never breaking a single word that's longer than
+/// the available width on its own. Returns the wrapped lines joined by
+/// a newline plus `indent` spaces, ready to be appended after a header
+/// that's already been padded out to `indent` columns.
+fn wrap_description(description: &str, indent: usize, width: usize) -> String {
+    let available = width.saturating_sub(indent).max(1);
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+
+    for word in description.split_whitespace() {
+        if current.is_empty() {
+            current.push_str(word);
+        } else if display_width(&current) + 1 + display_width(word) <= available {
+            current.push(' ');
+            current.push_str(word);
+        } else {
+            lines.push(std::mem::take(&mut current));
+            current.push_str(word);
+        }
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+
+    lines.join(&("\n".to_owned() + &" ".repeat(indent)))
+}
+
+/// Renders one `help()` entry: `header`, padded out to `description_column`
+/// (or followed by a newline and indented if `header` itself doesn't fit),
+/// then its wrapped description.
+fn render_help_entry(header: &str, description: &str, description_column: usize, width: usize) -> String {
+    let header_width = display_width(header);
+    let wrapped = wrap_description(description, description_column, width);
+    if header_width + 2 <= description_column {
+        let pad = " ".repeat(description_column - header_width);
+        format!("{}{}{}\n", header, pad, wrapped)
+    } else {
+        let indent = " ".repeat(description_column);
+        format!("{}\n{}{}\n", header, indent, wrapped)
+    }
+}
+
+/// Escapes `value` for embedding inside a double-quoted shell string, as
+/// used for descriptions and choice values in the bash/fish completion
+/// scripts. Escapes `$` and `` ` `` in addition to the quote itself,
+/// since bash still expands both inside a double-quoted string (e.g.
+/// `$(...)` command substitution) even once the quote can't be broken
+/// out of.
+fn escape_double_quoted(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('$', "\\$")
+        .replace('`', "\\`")
+}
+
+/// Escapes `value` for embedding inside a POSIX single-quoted string, as
+/// used for descriptions and choice values in the zsh completion script:
+/// ends the quote, emits a shell-escaped quote, then reopens it.
+fn escape_single_quoted(value: &str) -> String {
+    value.replace('\'', "'\\''")
+}
+
+/// Escapes `value` for embedding inside a single-quoted string in shells
+/// that represent a literal quote by doubling it, as used for the word
+/// lists in the PowerShell and Elvish completion scripts.
+fn escape_doubled_single_quoted(value: &str) -> String {
+    value.replace('\'', "''")
 }
 
 /// A flag structure meant to be passed to the flags vec in a Parser.
@@ -109,6 +265,12 @@ pub struct Parser {
 ///         title: "a",
 ///         description: "The a flag",
 ///         options: vec!["option".to_string()],
+///         value_type: ValueType::Str,
+///         validator: None,
+///         long: None,
+///         required: false,
+///         default: None,
+///         env: None,
 ///     }
 /// }
 /// ```
@@ -116,6 +278,57 @@ pub struct Flag {
     title: String,
     description: String,
     options: Vec<String>,
+    value_type: ValueType,
+    validator: Option<Box<dyn Fn(&str) -> Result<(), String>>>,
+    /// The `--name` this flag also answers to, if any. Set via
+    /// [`create_flag_long`].
+    long: Option<String>,
+    /// Whether [`Parser::parse`] should fail with
+    /// [`ParseError::MissingRequiredFlag`] if this flag is still unset
+    /// after environment/default fallback.
+    required: bool,
+    /// The value to fall back to when this flag isn't passed and its
+    /// `env` variable (if any) isn't set either.
+    default: Option<String>,
+    /// An environment variable to fall back to when this flag isn't
+    /// passed on the command line.
+    env: Option<String>,
+}
+
+/// How a `-`-prefixed token resolved against a parser's declared flags,
+/// returned by the private `Parser::tokenize_flag` helper.
+enum FlagToken<'a> {
+    /// `-h`/`--help` was seen.
+    Help,
+    /// `-V`/`--version` was seen.
+    Version,
+    /// A single flag was matched, along with an inline `--name=value`
+    /// value if one was given.
+    Flag(&'a Flag, Option<String>),
+    /// A cluster of single-character, zero-argument flags, e.g. `-abc`.
+    Cluster(Vec<&'a Flag>),
+    /// No flag (or cluster) matched; carries the lowercased name.
+    Unknown(String),
+}
+
+/// The expected shape of a flag's value. Checked against the raw token
+/// collected by [`Parser::parse`] before it's stored in [`ParsedArgs`].
+pub enum ValueType {
+    Str,
+    Int,
+    Float,
+    Bool,
+    Choice(Vec<String>),
+}
+
+/// A shell flavor that [`Parser::generate_completion`] can emit a
+/// completion script for.
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+    PowerShell,
+    Elvish,
 }
 
 /// A collection of the flags, and associated options, as well as
@@ -138,14 +351,107 @@ pub struct Flag {
 /// 
 ///     let arg_parser = create_parser("Test Parser", "Tests arguments", flags, args);
 /// 
-///     let parsed_args: ParsedArgs = arg_parser.parse(&mut std::env::args());
+///     let parsed_args: ParsedArgs = arg_parser.parse(&mut std::env::args()).unwrap();
 /// }
 /// ```
 pub struct ParsedArgs {
     pub flags: Vec<(String, String)>,
     pub arguments: Vec<String>,
+    /// The subcommand that was selected, if the parser that produced
+    /// these results declares any `subcommands`.
+    pub subcommand: Option<String>,
+}
+
+impl ParsedArgs {
+    /// Returns the raw value collected for `flag`, if it was passed or
+    /// filled in from its environment variable or default.
+    pub fn get_str(&self, flag: &str) -> Option<&str> {
+        self.flags.iter()
+            .find(|(title, _)| title == flag)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the value collected for `flag` parsed as an `i64`.
+    pub fn get_int(&self, flag: &str) -> Option<i64> {
+        self.get_str(flag).and_then(|value| value.parse().ok())
+    }
+
+    /// Returns the value collected for `flag` parsed as an `f64`.
+    pub fn get_float(&self, flag: &str) -> Option<f64> {
+        self.get_str(flag).and_then(|value| value.parse().ok())
+    }
+
+    /// Returns the value collected for `flag` parsed as a `bool`.
+    pub fn get_bool(&self, flag: &str) -> Option<bool> {
+        self.get_str(flag).and_then(|value| value.parse().ok())
+    }
+}
+
+/// The ways that [`Parser::parse`] can fail. Callers decide whether to
+/// print [`Parser::help`] and exit, retry, or surface the error some
+/// other way.
+#[derive(Debug)]
+pub enum ParseError {
+    /// A `-flag` token didn't match any flag known to the parser.
+    UnknownFlag(String),
+    /// The same flag was passed more than once.
+    DuplicateFlag(String),
+    /// A flag that takes options wasn't followed by one.
+    MissingValue(String),
+    /// A flag that takes no options was followed by one anyway.
+    UnexpectedValue(String),
+    /// A positional token didn't match any declared argument.
+    UnknownArgument(String),
+    /// The same argument was passed more than once.
+    DuplicateArgument(String),
+    /// A flag's value failed its [`ValueType`] check or custom validator.
+    InvalidValue {
+        flag: String,
+        value: String,
+        reason: String,
+    },
+    /// `-h`/`--help` was passed; carries the rendered help text for
+    /// whichever parser (or subcommand) was active when it was seen.
+    HelpRequested(String),
+    /// `-V`/`--version` was passed; carries the rendered version text for
+    /// whichever parser (or subcommand) was active when it was seen.
+    VersionRequested(String),
+    /// A `required` flag was still unset after environment/default
+    /// fallback once all tokens were consumed.
+    MissingRequiredFlag(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::UnknownFlag(flag) => write!(f, "Invalid flag: '{}'", flag),
+            ParseError::DuplicateFlag(flag) => {
+                write!(f, "Flags may only be used once, duplicate flag: -{}", flag)
+            }
+            ParseError::MissingValue(flag) => {
+                write!(f, "-{} expected an option but none was given", flag)
+            }
+            ParseError::UnexpectedValue(flag) => {
+                write!(f, "-{} does not take any options", flag)
+            }
+            ParseError::UnknownArgument(arg) => write!(f, "Unknown arg: '{}'", arg),
+            ParseError::DuplicateArgument(arg) => {
+                write!(f, "Arguments may only be used once, duplicate argument: {}", arg)
+            }
+            ParseError::InvalidValue { flag, value, reason } => {
+                write!(f, "-{} got invalid value '{}': {}", flag, value, reason)
+            }
+            ParseError::HelpRequested(help) => write!(f, "{}", help),
+            ParseError::VersionRequested(version) => write!(f, "{}", version),
+            ParseError::MissingRequiredFlag(flag) => {
+                write!(f, "-{} is required but was not provided", flag)
+            }
+        }
+    }
 }
 
+impl Error for ParseError {}
+
 impl Flag {
     /// Creates a new and empty flag to be edited further.
     /// 
@@ -162,7 +468,147 @@ impl Flag {
             title: String::new(),
             description: String::new(),
             options: Vec::new(),
+            value_type: ValueType::Str,
+            validator: None,
+            long: None,
+            required: false,
+            default: None,
+            env: None,
+        }
+    }
+
+    /// Sets the `--name` this flag also answers to, alongside its
+    /// existing `-x` short form.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use argparser::*;
+    ///
+    /// fn main() {
+    ///     let mut all_flag = create_flag("a", "Include all entries", vec![]);
+    ///     all_flag.set_long("all");
+    /// }
+    /// ```
+    pub fn set_long(&mut self, long: &str) {
+        self.long = Some(long.to_ascii_lowercase());
+    }
+
+    /// Marks this flag as required. If it's still unset after
+    /// environment/default fallback once all tokens are consumed,
+    /// [`Parser::parse`] returns [`ParseError::MissingRequiredFlag`].
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use argparser::*;
+    ///
+    /// fn main() {
+    ///     let mut port_flag = create_flag("p", "Port to listen on", vec!["port"]);
+    ///     port_flag.set_required(true);
+    /// }
+    /// ```
+    pub fn set_required(&mut self, required: bool) {
+        self.required = required;
+    }
+
+    /// Sets the value this flag falls back to when it isn't passed and
+    /// its [`Flag::set_env`] variable (if any) isn't set either.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use argparser::*;
+    ///
+    /// fn main() {
+    ///     let mut port_flag = create_flag("p", "Port to listen on", vec!["port"]);
+    ///     port_flag.set_default("8080");
+    /// }
+    /// ```
+    pub fn set_default(&mut self, default: &str) {
+        self.default = Some(default.to_string());
+    }
+
+    /// Sets an environment variable this flag falls back to when it
+    /// isn't passed on the command line.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use argparser::*;
+    ///
+    /// fn main() {
+    ///     let mut port_flag = create_flag("p", "Port to listen on", vec!["port"]);
+    ///     port_flag.set_env("APP_PORT");
+    /// }
+    /// ```
+    pub fn set_env(&mut self, env: &str) {
+        self.env = Some(env.to_string());
+    }
+
+    /// Sets the expected value type for this flag, checked during
+    /// [`Parser::parse`].
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use argparser::*;
+    ///
+    /// fn main() {
+    ///     let mut port_flag = create_flag("p", "Port to listen on", vec!["port"]);
+    ///     port_flag.set_value_type(ValueType::Int);
+    /// }
+    /// ```
+    pub fn set_value_type(&mut self, value_type: ValueType) {
+        self.value_type = value_type;
+    }
+
+    /// Attaches a custom validator that runs after the built-in
+    /// [`ValueType`] check. Return `Err(reason)` to reject the value.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use argparser::*;
+    ///
+    /// fn main() {
+    ///     let mut port_flag = create_flag("p", "Port to listen on", vec!["port"]);
+    ///     port_flag.set_validator(Box::new(|value| {
+    ///         match value.parse::<u16>() {
+    ///             Ok(_) => Ok(()),
+    ///             Err(_) => Err(format!("'{}' is not a valid port", value)),
+    ///         }
+    ///     }));
+    /// }
+    /// ```
+    pub fn set_validator(&mut self, validator: Box<dyn Fn(&str) -> Result<(), String>>) {
+        self.validator = Some(validator);
+    }
+
+    /// Runs the value type check, then the custom validator (if any),
+    /// against a raw token collected for this flag.
+    fn validate(&self, value: &str) -> Result<(), String> {
+        match &self.value_type {
+            ValueType::Str => {}
+            ValueType::Int => {
+                if value.parse::<i64>().is_err() {
+                    return Err(format!("'{}' is not a valid integer", value));
+                }
+            }
+            ValueType::Float => {
+                if value.parse::<f64>().is_err() {
+                    return Err(format!("'{}' is not a valid float", value));
+                }
+            }
+            ValueType::Bool => {
+                if value.parse::<bool>().is_err() {
+                    return Err(format!("'{}' is not a valid boolean", value));
+                }
+            }
+            ValueType::Choice(choices) => {
+                if !choices.iter().any(|choice| choice == value) {
+                    return Err(format!("'{}' is not one of {:?}", value, choices));
+                }
+            }
         }
+        if let Some(validator) = &self.validator {
+            validator(value)?;
+        }
+        Ok(())
     }
 }
 
@@ -227,76 +673,251 @@ impl Parser {
     /// }
     /// ```
     /// 
-    /// calling `arg_parser.help()` will generate the following:
-    /// 
+    /// calling `arg_parser.help()` will generate the following (assuming
+    /// an 80-column terminal, the default when one can't be detected):
+    ///
     /// ``` txt
     /// Test Parser, Tests arguments
-    /// Usage: -h for help:
+    /// Usage: Test Parser [-a <some>] [-b <some> <thing>] [-c <some>] [-d] foo bar
     ///
     /// Options:
-    ///    -a <some> :
-    ///	        This is the a flag
-    ///    -b <some> <thing> :
-    ///	        This is the b flag
-    ///    -c <some> :
-    ///     	 This is the c flag
-    ///    -d :
-    ///	        This is the d flag
+    ///     -a <some>          This is the a flag
+    ///     -b <some> <thing>  This is the b flag
+    ///     -c <some>          This is the c flag
+    ///     -d                 This is the d flag
     ///
     /// Arguments:
-    ///    foo :
-    ///     	 This is the foo argument
-    ///    bar :
-    ///     	 This is the bar argument
+    ///     foo                This is the foo argument
+    ///     bar                This is the bar argument
     /// ```
+    ///
+    /// Descriptions that don't fit the terminal width are wrapped and
+    /// aligned to the same column; see [`Parser::help_width_override`]
+    /// to pin that width (handy in tests).
     pub fn help(&self) -> String {
+        let width = self.help_width.unwrap_or_else(terminal_width);
+
+        // A "header" is the part of a line before its description, e.g.
+        // "    -a <some>" or "    foo". Every description lines up in the
+        // same column, one past the widest header.
+        let flag_headers: Vec<(String, String)> = self.flags.iter().map(|flag| {
+            let mut header = "    -".to_owned() + &flag.title;
+            if let Some(long) = &flag.long {
+                header.push_str(&(", --".to_owned() + long));
+            }
+            for option in &flag.options {
+                header.push_str(&(" <".to_owned() + option + ">"));
+            }
+            let mut description = flag.description.clone();
+            if flag.required {
+                description.push_str(" (required)");
+            } else if let Some(default) = &flag.default {
+                description.push_str(&format!(" (default: {})", default));
+            }
+            (header, description)
+        }).collect();
+
+        let arg_headers: Vec<(String, String)> = self.arguments.iter()
+            .map(|arg| ("    ".to_owned() + &arg.title, arg.description.clone()))
+            .collect();
+
+        let subcommand_headers: Vec<(String, String)> = self.subcommands.iter()
+            .map(|subcommand| ("    ".to_owned() + &subcommand.project_title, subcommand.project_description.clone()))
+            .collect();
+
+        let description_column = flag_headers.iter()
+            .chain(arg_headers.iter())
+            .chain(subcommand_headers.iter())
+            .map(|(header, _)| display_width(header))
+            .max()
+            .unwrap_or(0) + 2;
+
         // Get the flags
         let mut flag_str: String = String::new();
-        if self.flags.len() > 0 {
+        if !flag_headers.is_empty() {
             flag_str.push_str(" Options:\n");
-            for flag in &self.flags {
-                flag_str.push_str(&("    -".to_owned() + &flag.title + " "));
-                for option in &flag.options {
-                    flag_str.push_str(&("<".to_owned() + option + "> "));
-                }
-                flag_str.push_str(&(":\n\t ".to_owned() + &flag.description + "\n"));
+            for (header, description) in &flag_headers {
+                flag_str.push_str(&render_help_entry(header, description, description_column, width));
             }
             flag_str.push_str("\n");
         }
-        
+
         // Get the arguments
         let mut args_str = String::new();
-        if self.arguments.len() > 0 {
+        if !arg_headers.is_empty() {
             args_str.push_str(" Arguments:\n");
-            for arg in &self.arguments {
-                args_str.push_str(&("    ".to_owned() + &arg.title + " :\n\t " + &arg.description + "\n"));
-            }  
+            for (header, description) in &arg_headers {
+                args_str.push_str(&render_help_entry(header, description, description_column, width));
+            }
+        }
+
+        // Get the subcommands
+        let mut subcommands_str = String::new();
+        if !subcommand_headers.is_empty() {
+            subcommands_str.push_str("\n Subcommands:\n");
+            for (header, description) in &subcommand_headers {
+                subcommands_str.push_str(&render_help_entry(header, description, description_column, width));
+            }
         }
-        
+
         // Create the help message
-        let mut help_msg = 
-            self.project_title.clone() + ", " + &self.project_description.clone() +
-            "\nUsage: -h for help:\n\n";
+        let mut help_msg = self.project_title.clone() + ", " + &self.project_description.clone() + "\n";
+        if let Some(version) = &self.version {
+            help_msg.push_str("Version: ");
+            help_msg.push_str(version);
+            help_msg.push('\n');
+        }
+        help_msg.push_str(&self.usage_line());
+        help_msg.push('\n');
+        if let Some(usage_example) = &self.usage_example {
+            help_msg.push_str("Example: ");
+            help_msg.push_str(usage_example);
+            help_msg.push('\n');
+        }
+        help_msg.push('\n');
         help_msg.push_str(&flag_str);
         help_msg.push_str(&args_str);
+        help_msg.push_str(&subcommands_str);
         help_msg
     }
 
+    /// Builds the concrete `Usage:` line shown by [`Parser::help`], from
+    /// this parser's own declared flags and positional arguments.
+    fn usage_line(&self) -> String {
+        let mut usage = "Usage: ".to_owned() + &self.project_title;
+        for flag in &self.flags {
+            usage.push_str(" [-");
+            usage.push_str(&flag.title);
+            for option in &flag.options {
+                usage.push_str(&(" <".to_owned() + option + ">"));
+            }
+            usage.push(']');
+        }
+        for argument in &self.arguments {
+            usage.push(' ');
+            usage.push_str(&argument.title);
+        }
+        if !self.subcommands.is_empty() {
+            usage.push_str(" <command>");
+        }
+        usage
+    }
+
+    /// Renders the text printed for `-V`/`--version`: the project title
+    /// and version, plus the author and copyright when set.
+    fn version_text(&self) -> String {
+        let mut text = self.project_title.clone();
+        if let Some(version) = &self.version {
+            text.push(' ');
+            text.push_str(version);
+        }
+        if let Some(author) = &self.author {
+            text.push('\n');
+            text.push_str(author);
+        }
+        if let Some(copyright) = &self.copyright {
+            text.push('\n');
+            text.push_str(copyright);
+        }
+        text
+    }
+
+    /// Sets the version string shown by [`Parser::help`] and printed for
+    /// `-V`/`--version`.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use argparser::*;
+    ///
+    /// fn main() {
+    ///     let mut arg_parser = create_parser("Test Parser", "Tests arguments", Vec::new(), Vec::new());
+    ///     arg_parser.set_version("1.0.0");
+    /// }
+    /// ```
+    pub fn set_version(&mut self, version: &str) {
+        self.version = Some(version.to_string());
+    }
+
+    /// Sets the author shown alongside the version for `-V`/`--version`.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use argparser::*;
+    ///
+    /// fn main() {
+    ///     let mut arg_parser = create_parser("Test Parser", "Tests arguments", Vec::new(), Vec::new());
+    ///     arg_parser.set_author("Jane Doe");
+    /// }
+    /// ```
+    pub fn set_author(&mut self, author: &str) {
+        self.author = Some(author.to_string());
+    }
+
+    /// Sets the copyright notice shown alongside the version for
+    /// `-V`/`--version`.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use argparser::*;
+    ///
+    /// fn main() {
+    ///     let mut arg_parser = create_parser("Test Parser", "Tests arguments", Vec::new(), Vec::new());
+    ///     arg_parser.set_copyright("Copyright (c) 2026 Jane Doe");
+    /// }
+    /// ```
+    pub fn set_copyright(&mut self, copyright: &str) {
+        self.copyright = Some(copyright.to_string());
+    }
+
+    /// Sets a worked example shown underneath the `Usage:` line in
+    /// [`Parser::help`], e.g. `prog -a some foo`.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use argparser::*;
+    ///
+    /// fn main() {
+    ///     let mut arg_parser = create_parser("Test Parser", "Tests arguments", Vec::new(), Vec::new());
+    ///     arg_parser.set_usage_example("prog -a some foo");
+    /// }
+    /// ```
+    pub fn set_usage_example(&mut self, usage_example: &str) {
+        self.usage_example = Some(usage_example.to_string());
+    }
+
+    /// Pins the width used to wrap [`Parser::help`] output, bypassing
+    /// terminal detection. Mainly useful so tests get deterministic
+    /// wrapping regardless of the environment they run in.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use argparser::*;
+    ///
+    /// fn main() {
+    ///     let mut arg_parser = create_parser("Test Parser", "Tests arguments", Vec::new(), Vec::new());
+    ///     arg_parser.help_width_override(40);
+    /// }
+    /// ```
+    pub fn help_width_override(&mut self, width: usize) {
+        self.help_width = Some(width);
+    }
+
     /// Parses the arguemnts that are passed into the program by
-    /// comparing them to the Parser arguments. If the parsing
-    /// fails then the program terminates. If the parsing is
-    /// successful, then it returns the parsed args.
-    /// 
+    /// comparing them to the Parser arguments. Returns `Err(ParseError)`
+    /// if the arguments don't match what this parser expects, leaving it
+    /// up to the caller to decide whether to print [`Parser::help`] and
+    /// exit, or handle the error some other way.
+    ///
     /// # Arguments
     /// - args: &mut Args
-    /// 
+    ///
     /// # Returns
-    /// ParsedArgs
-    /// 
+    /// Result<ParsedArgs, ParseError>
+    ///
     /// # Examples
     /// ``` rust
     /// use argparser::*;
-    /// 
+    ///
     /// fn main() {
     ///     let mut flags: Vec<Flag> = Vec::new();
     ///     flags.push(create_flag("a", "This is the a flag", vec!["some"]));
@@ -309,101 +930,148 @@ impl Parser {
     ///     args.push(create_arg("bar", "This is the bar argument"));
     ///
     ///     let arg_parser = create_parser("Test Parser", "Tests arguments", flags, args);
-    /// 
-    ///     let parsed_args: ParsedArgs = arg_parser.parse(&mut std::env::args());
+    ///
+    ///     match arg_parser.parse(&mut std::env::args()) {
+    ///         Ok(parsed_args) => { let _ = parsed_args; },
+    ///         Err(err) => {
+    ///             eprintln!("{}\n{}", err, arg_parser.help());
+    ///         }
+    ///     }
     /// }
     /// ```
-    pub fn parse(&self, args: &mut Args) -> ParsedArgs {
+    pub fn parse(&self, args: &mut Args) -> Result<ParsedArgs, ParseError> {
         // First argument is the programs path. Skip it.
         args.next();
+        self.parse_from(args)
+    }
 
+    /// Does the actual token-by-token parsing. Split out from [`Parser::parse`]
+    /// so a subcommand can be handed the rest of the same token stream
+    /// without re-skipping the program path.
+    fn parse_from(&self, args: &mut dyn Iterator<Item = String>) -> Result<ParsedArgs, ParseError> {
         // Set up "globals" and return Options
         let mut is_option = false;
         let mut parsed = false;
+        let mut first_positional = true;
         let mut current_flag: &Flag = &Flag::new();
         let mut used_flags: Vec<&Flag> = Vec::new();
         let mut used_args: Vec<&Argument> = Vec::new();
-        let mut options: ParsedArgs = ParsedArgs { flags: Vec::new(), arguments: Vec::new() };
-
-        'args: for arg in args{
-            // First character of arg is '-', meaning it's a flag
-            if arg.chars().nth(0) == Some('-') && !is_option {
-                // Since we are at an option, check is_option to true.
-                is_option = true;
-                let arg_to_lower = arg[1..].to_ascii_lowercase();
-                for flag in &self.flags {
-                    // Check to make sure the flag hasn't been used already.
-                    for used_flag in &used_flags {
-                        if arg_to_lower == used_flag.title {
-                            println!("Flags may only be used once, duplicate flag: -{}...\n{}", arg_to_lower, self.help());
-                            process::exit(1);
-                        }
-                    }
-                    if arg_to_lower == *flag.title {
-                        current_flag = flag;
-                        used_flags.push(&flag);
-                        parsed = false;
-                        continue 'args;
+        let mut options: ParsedArgs = ParsedArgs {
+            flags: Vec::new(),
+            arguments: Vec::new(),
+            subcommand: None,
+        };
+
+        'args: while let Some(arg) = args.next() {
+            // First character of arg is '-', meaning it's a flag (or a
+            // cluster/long-form flag; see `tokenize_flag`).
+            if arg.chars().nth(0) == Some('-') {
+                // A flag may only be followed by another flag if it
+                // doesn't take any arguments.
+                if is_option {
+                    if current_flag.options.len() > 0 {
+                        return Err(ParseError::MissingValue(current_flag.title.clone()));
                     }
+                    options.flags.push((current_flag.title.clone(), String::new()));
+                    is_option = false;
                 }
-                if arg_to_lower == "h" {
-                    println!("{}", self.help());
-                    process::exit(1);
-                }
-                else {
-                    println!("Invalid flag: '{}'...\n{}", arg_to_lower, self.help());
-                    process::exit(1);
-                }
-            }
-            // Flags may only be followed by another flag if they don't take any arguments
-            else if arg.chars().nth(0) == Some('-') && is_option {
-                options.flags.push((current_flag.title.clone(), String::new()));
-                // parsed = true;
-                let arg_to_lower = arg[1..].to_ascii_lowercase();
-                for flag in &self.flags {
-                    // Check to make sure the flag hasn't been used already.
-                    for used_flag in &used_flags {
-                        if arg_to_lower == used_flag.title {
-                            println!("Flags may only be used once, duplicate flag: -{}...\n{}", arg_to_lower, self.help());
-                            process::exit(1);
+
+                match self.tokenize_flag(&arg) {
+                    FlagToken::Help => return Err(ParseError::HelpRequested(self.help())),
+                    FlagToken::Version => return Err(ParseError::VersionRequested(self.version_text())),
+                    FlagToken::Unknown(name) => return Err(ParseError::UnknownFlag(name)),
+                    FlagToken::Cluster(flags) => {
+                        for flag in flags {
+                            for used_flag in &used_flags {
+                                if used_flag.title == flag.title {
+                                    return Err(ParseError::DuplicateFlag(flag.title.clone()));
+                                }
+                            }
+                            used_flags.push(flag);
+                            options.flags.push((flag.title.clone(), String::new()));
                         }
+                        parsed = true;
                     }
-                    if arg_to_lower == *flag.title {
-                        current_flag = flag;
-                        used_flags.push(&flag);
-                        parsed = false;
-                        continue 'args;
+                    FlagToken::Flag(flag, inline_value) => {
+                        for used_flag in &used_flags {
+                            if used_flag.title == flag.title {
+                                return Err(ParseError::DuplicateFlag(flag.title.clone()));
+                            }
+                        }
+                        used_flags.push(flag);
+                        match inline_value {
+                            Some(value) => {
+                                if flag.options.len() == 0 {
+                                    return Err(ParseError::UnexpectedValue(flag.title.clone()));
+                                }
+                                if let Err(reason) = flag.validate(&value) {
+                                    return Err(ParseError::InvalidValue {
+                                        flag: flag.title.clone(),
+                                        value,
+                                        reason,
+                                    });
+                                }
+                                options.flags.push((flag.title.clone(), value));
+                                parsed = true;
+                            }
+                            None => {
+                                current_flag = flag;
+                                is_option = true;
+                                parsed = false;
+                            }
+                        }
                     }
                 }
-                if arg_to_lower == "h" {
-                    println!("{}", self.help());
-                    process::exit(1);
-                }
-                else {
-                    println!("Invalid flag, '{}'...\n{}", arg_to_lower, self.help());
-                    process::exit(1);
-                }
+                continue 'args;
             }
             // Flags that do take arguments, check to make sure the option following is correct.
-            else if arg.chars().nth(0) != Some('-') && is_option {
+            else if is_option {
                 if current_flag.options.len() == 0 {
-                    println!("-{} does not take any arguments...\n{}", current_flag.title, self.help());
-                    process::exit(1);
+                    return Err(ParseError::UnexpectedValue(current_flag.title.clone()));
                 }
-                let arg_to_lower = arg[..].to_ascii_lowercase();
-                options.flags.push((current_flag.title.clone(), arg_to_lower));
+                let value = arg.clone();
+                if let Err(reason) = current_flag.validate(&value) {
+                    return Err(ParseError::InvalidValue {
+                        flag: current_flag.title.clone(),
+                        value,
+                        reason,
+                    });
+                }
+                options.flags.push((current_flag.title.clone(), value));
                 parsed = true;
                 is_option = false;
             }
             // Check if an arguemnt is passed in.
             else {
                 let arg_to_lower = arg[..].to_ascii_lowercase();
+                // The first positional token may select a subcommand,
+                // which takes over parsing the rest of the tokens.
+                if first_positional {
+                    first_positional = false;
+                    for subcommand in &self.subcommands {
+                        if subcommand.project_title.to_ascii_lowercase() == arg_to_lower {
+                            // This parser won't see any more tokens once
+                            // we delegate, so its own required/default/env
+                            // fallback has to run now rather than falling
+                            // through to the end of the function.
+                            self.apply_flag_fallbacks(&mut options)?;
+                            let mut sub_options = subcommand.parse_from(args)?;
+                            sub_options.subcommand = Some(subcommand.project_title.clone());
+                            // Our own flags were consumed before the
+                            // subcommand name appeared, so they're not in
+                            // sub_options yet; merge them in rather than
+                            // letting them fall out of the result.
+                            options.flags.extend(sub_options.flags);
+                            sub_options.flags = options.flags;
+                            return Ok(sub_options);
+                        }
+                    }
+                }
                 for parser_arg in &self.arguments {
                     // Check to make sure the argument hasn't been used already.
                     for used_arg in &used_args {
                         if arg_to_lower == used_arg.title {
-                            println!("Arguments may only be used once, duplicate argument: {}...\n{}", arg_to_lower, self.help());
-                            process::exit(1);
+                            return Err(ParseError::DuplicateArgument(arg_to_lower));
                         }
                     }
                     if parser_arg.title == arg_to_lower {
@@ -412,19 +1080,300 @@ impl Parser {
                         continue 'args;
                     }
                 }
-                println!("Uknown arg: '{}'...\n{}", arg, self.help());
-                process::exit(1);
+                return Err(ParseError::UnknownArgument(arg_to_lower));
             }
         }
+        if is_option && current_flag.options.len() > 0 && !parsed {
+            return Err(ParseError::MissingValue(current_flag.title.clone()));
+        }
         if !parsed {
             options.flags.push((current_flag.title.clone(), String::new()));
         }
-        options
+
+        self.apply_flag_fallbacks(&mut options)?;
+        Ok(options)
     }
-}
 
-/// Creates a Flag which contains a title, descriptions, and the options that it takes.
-/// 
+    /// Fills any of this parser's flags that weren't passed, from
+    /// `flag.env` then `flag.default` (validated the same as an
+    /// explicit token); returns `Err(MissingRequiredFlag)` for a
+    /// `required` flag still unset afterward. Must run before
+    /// delegating to a subcommand, since this parser won't see any of
+    /// the remaining tokens once that happens.
+    fn apply_flag_fallbacks(&self, options: &mut ParsedArgs) -> Result<(), ParseError> {
+        for flag in &self.flags {
+            if options.flags.iter().any(|(title, _)| title == &flag.title) {
+                continue;
+            }
+            let fallback = flag.env.as_deref()
+                .and_then(|env| std::env::var(env).ok())
+                .or_else(|| flag.default.clone());
+            if let Some(value) = fallback {
+                if let Err(reason) = flag.validate(&value) {
+                    return Err(ParseError::InvalidValue {
+                        flag: flag.title.clone(),
+                        value,
+                        reason,
+                    });
+                }
+                options.flags.push((flag.title.clone(), value));
+                continue;
+            }
+            if flag.required {
+                return Err(ParseError::MissingRequiredFlag(flag.title.clone()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Matches a `-`-prefixed token against this parser's flags: a short
+    /// flag (`-a`), a long flag (`--all`, optionally `--all=value`), or a
+    /// cluster of short flags that all take no arguments (`-abc`).
+    fn tokenize_flag(&self, token: &str) -> FlagToken<'_> {
+        if let Some(rest) = token.strip_prefix("--") {
+            let (name, inline_value) = match rest.split_once('=') {
+                Some((name, value)) => (name.to_ascii_lowercase(), Some(value.to_string())),
+                None => (rest.to_ascii_lowercase(), None),
+            };
+            if name == "help" {
+                return FlagToken::Help;
+            }
+            if name == "version" {
+                return FlagToken::Version;
+            }
+            return match self.flags.iter().find(|flag| {
+                flag.long.as_deref().map(|long| long.eq_ignore_ascii_case(&name)).unwrap_or(false)
+            }) {
+                Some(flag) => FlagToken::Flag(flag, inline_value),
+                None => FlagToken::Unknown(name),
+            };
+        }
+
+        let rest = &token[1..];
+        let name = rest.to_ascii_lowercase();
+        if name == "h" {
+            return FlagToken::Help;
+        }
+        if name == "v" {
+            return FlagToken::Version;
+        }
+        if let Some(flag) = self.flags.iter().find(|flag| flag.title == name) {
+            return FlagToken::Flag(flag, None);
+        }
+
+        // Not a single known flag; see if it's a cluster of single-char
+        // flags that all take no arguments, e.g. `-abc` == `-a -b -c`.
+        if rest.chars().count() > 1 {
+            let mut cluster = Vec::new();
+            for ch in rest.chars() {
+                let ch_name = ch.to_ascii_lowercase().to_string();
+                match self.flags.iter().find(|flag| flag.title == ch_name) {
+                    Some(flag) if flag.options.len() == 0 => cluster.push(flag),
+                    _ => return FlagToken::Unknown(name),
+                }
+            }
+            return FlagToken::Cluster(cluster);
+        }
+
+        FlagToken::Unknown(name)
+    }
+
+    /// Attaches a subcommand to this parser, keyed by the subcommand's
+    /// own `project_title`.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use argparser::*;
+    ///
+    /// fn main() {
+    ///     let mut parser = create_parser("git", "A version control system", Vec::new(), Vec::new());
+    ///     let commit = create_subcommand("commit", "Record changes", Vec::new(), Vec::new());
+    ///     parser.add_subcommand(commit);
+    /// }
+    /// ```
+    pub fn add_subcommand(&mut self, subcommand: Parser) {
+        self.subcommands.push(subcommand);
+    }
+
+    /// Generates a shell completion script offering this parser's flag
+    /// names (both `-x` and `--name` once a long form is set),
+    /// subcommand names, and `Choice`-typed option values as candidates.
+    /// The PowerShell and Elvish scripts offer only the flag and
+    /// subcommand names; they do not complete `Choice` values.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use argparser::*;
+    ///
+    /// fn main() {
+    ///     let arg_parser = create_parser("myprog", "An example program", Vec::new(), Vec::new());
+    ///     let script = arg_parser.generate_completion(Shell::Bash);
+    ///     let _ = script;
+    /// }
+    /// ```
+    pub fn generate_completion(&self, shell: Shell) -> String {
+        match shell {
+            Shell::Bash => self.generate_bash_completion(),
+            Shell::Zsh => self.generate_zsh_completion(),
+            Shell::Fish => self.generate_fish_completion(),
+            Shell::PowerShell => self.generate_powershell_completion(),
+            Shell::Elvish => self.generate_elvish_completion(),
+        }
+    }
+
+    /// Every candidate this parser's flags (short and long forms),
+    /// built-in `-h`/`--help` and `-V`/`--version`, and subcommand names
+    /// complete to, flattened into one list.
+    fn completion_words(&self) -> Vec<String> {
+        let mut words: Vec<String> = Vec::new();
+        for flag in &self.flags {
+            words.push("-".to_owned() + &flag.title);
+            if let Some(long) = &flag.long {
+                words.push("--".to_owned() + long);
+            }
+        }
+        words.push("-h".to_string());
+        words.push("--help".to_string());
+        words.push("-V".to_string());
+        words.push("--version".to_string());
+        for subcommand in &self.subcommands {
+            words.push(subcommand.project_title.clone());
+        }
+        words
+    }
+
+    /// The flags (and their short/long names) that declare a `Choice`
+    /// value type, paired with the choices themselves, e.g. for
+    /// completing the value that follows `-m`/`--mode`.
+    fn choice_flags(&self) -> Vec<(Vec<String>, &[String])> {
+        self.flags.iter().filter_map(|flag| {
+            let ValueType::Choice(choices) = &flag.value_type else { return None };
+            let mut names = vec!["-".to_owned() + &flag.title];
+            if let Some(long) = &flag.long {
+                names.push("--".to_owned() + long);
+            }
+            Some((names, choices.as_slice()))
+        }).collect()
+    }
+
+    fn generate_bash_completion(&self) -> String {
+        let program = &self.project_title;
+        let function_name = format!("_{}_complete", program.replace(' ', "_"));
+        let words: String = self.completion_words().iter()
+            .map(|word| escape_double_quoted(word))
+            .collect::<Vec<String>>()
+            .join(" ");
+
+        let mut cases = String::new();
+        for (names, choices) in self.choice_flags() {
+            let escaped_choices: Vec<String> = choices.iter().map(|c| escape_double_quoted(c)).collect();
+            cases.push_str(&format!("        {})\n", names.join("|")));
+            cases.push_str(&format!("            COMPREPLY=( $(compgen -W \"{}\" -- \"$cur\") )\n", escaped_choices.join(" ")));
+            cases.push_str("            return 0\n            ;;\n");
+        }
+
+        format!(
+            "{function_name}() {{\n    local cur prev\n    COMPREPLY=()\n    cur=\"${{COMP_WORDS[COMP_CWORD]}}\"\n    prev=\"${{COMP_WORDS[COMP_CWORD-1]}}\"\n\n    case \"$prev\" in\n{cases}    esac\n\n    COMPREPLY=( $(compgen -W \"{words}\" -- \"$cur\") )\n}}\ncomplete -F {function_name} {program}\n",
+            function_name = function_name,
+            cases = cases,
+            words = words,
+            program = program,
+        )
+    }
+
+    fn generate_zsh_completion(&self) -> String {
+        let program = &self.project_title;
+        let mut specs = String::new();
+        for flag in &self.flags {
+            let names: String = match &flag.long {
+                Some(long) => format!("'(-{} --{})'{{-{},--{}}}", flag.title, long, flag.title, long),
+                None => format!("'-{}'", flag.title),
+            };
+            let message = if let ValueType::Choice(choices) = &flag.value_type {
+                let escaped_choices: Vec<String> = choices.iter().map(|c| escape_single_quoted(c)).collect();
+                format!(":value:({})", escaped_choices.join(" "))
+            } else if !flag.options.is_empty() {
+                ":value:".to_string()
+            } else {
+                String::new()
+            };
+            specs.push_str(&format!("        {}'[{}]{}' \\\n", names, escape_single_quoted(&flag.description), message));
+        }
+        for subcommand in &self.subcommands {
+            specs.push_str(&format!(
+                "        '{}[{}]' \\\n",
+                escape_single_quoted(&subcommand.project_title),
+                escape_single_quoted(&subcommand.project_description),
+            ));
+        }
+
+        format!(
+            "#compdef {program}\n\n_arguments \\\n        '(-h --help)'{{-h,--help}}'[Show help]' \\\n        '(-V --version)'{{-V,--version}}'[Show version]' \\\n{specs}\n",
+            program = program,
+            specs = specs,
+        )
+    }
+
+    fn generate_fish_completion(&self) -> String {
+        let program = &self.project_title;
+        let mut lines = String::new();
+        for flag in &self.flags {
+            let mut line = format!("complete -c {} -s {}", program, flag.title);
+            if let Some(long) = &flag.long {
+                line.push_str(&format!(" -l {}", long));
+            }
+            if !flag.description.is_empty() {
+                line.push_str(&format!(" -d \"{}\"", escape_double_quoted(&flag.description)));
+            }
+            if let ValueType::Choice(choices) = &flag.value_type {
+                let escaped_choices: Vec<String> = choices.iter().map(|c| escape_double_quoted(c)).collect();
+                line.push_str(&format!(" -xa \"{}\"", escaped_choices.join(" ")));
+            }
+            lines.push_str(&line);
+            lines.push('\n');
+        }
+        lines.push_str(&format!("complete -c {} -s h -l help -d \"Show help\"\n", program));
+        lines.push_str(&format!("complete -c {} -s V -l version -d \"Show version\"\n", program));
+        for subcommand in &self.subcommands {
+            lines.push_str(&format!(
+                "complete -c {} -n \"__fish_use_subcommand\" -a {} -d \"{}\"\n",
+                program, subcommand.project_title, escape_double_quoted(&subcommand.project_description)
+            ));
+        }
+        lines
+    }
+
+    fn generate_powershell_completion(&self) -> String {
+        let program = &self.project_title;
+        let words = self.completion_words().iter()
+            .map(|word| format!("'{}'", escape_doubled_single_quoted(word)))
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        format!(
+            "Register-ArgumentCompleter -Native -CommandName {program} -ScriptBlock {{\n    param($wordToComplete, $commandAst, $cursorPosition)\n    @({words}) | Where-Object {{ $_ -like \"$wordToComplete*\" }} | ForEach-Object {{\n        [System.Management.Automation.CompletionResult]::new($_, $_, 'ParameterValue', $_)\n    }}\n}}\n",
+            program = program,
+            words = words,
+        )
+    }
+
+    fn generate_elvish_completion(&self) -> String {
+        let program = &self.project_title;
+        let words = self.completion_words().iter()
+            .map(|word| format!("'{}'", escape_doubled_single_quoted(word)))
+            .collect::<Vec<String>>()
+            .join(" ");
+
+        format!(
+            "set edit:completion:arg-completer[{program}] = {{|@args|\n    put {words}\n}}\n",
+            program = program,
+            words = words,
+        )
+    }
+}
+
+/// Creates a Flag which contains a title, descriptions, and the options that it takes.
+/// 
 /// # Arguments
 /// - title: &str
 /// - description: &str
@@ -450,9 +1399,67 @@ pub fn create_flag(title: &str, description: &str, options: Vec<&str>) -> Flag {
         title: title.to_string(),
         description: description.to_string(),
         options: return_vec,
+        value_type: ValueType::Str,
+        validator: None,
+        long: None,
+        required: false,
+        default: None,
+        env: None,
     }
 }
 
+/// Creates a Flag that also answers to a `--name` long form, alongside
+/// its `-x` short form.
+///
+/// # Arguments
+/// - title: &str
+/// - long: &str
+/// - description: &str
+/// - options: Vec<&str>
+///
+/// # Returns
+/// Flag
+///
+/// # Examples
+/// ``` rust
+/// use argparser::*;
+///
+/// fn main() {
+///     let all_flag = create_flag_long("a", "all", "Include all entries", vec![]);
+/// }
+/// ```
+pub fn create_flag_long(title: &str, long: &str, description: &str, options: Vec<&str>) -> Flag {
+    let mut flag = create_flag(title, description, options);
+    flag.set_long(long);
+    flag
+}
+
+/// Creates a Flag with a declared [`ValueType`], checked against the
+/// value collected for it during [`Parser::parse`].
+///
+/// # Arguments
+/// - title: &str
+/// - description: &str
+/// - options: Vec<&str>
+/// - value_type: ValueType
+///
+/// # Returns
+/// Flag
+///
+/// # Examples
+/// ``` rust
+/// use argparser::*;
+///
+/// fn main() {
+///     let port_flag = create_flag_typed("p", "Port to listen on", vec!["port"], ValueType::Int);
+/// }
+/// ```
+pub fn create_flag_typed(title: &str, description: &str, options: Vec<&str>, value_type: ValueType) -> Flag {
+    let mut flag = create_flag(title, description, options);
+    flag.value_type = value_type;
+    flag
+}
+
 /// Creates an Arguemnt that contains a title and description.
 /// 
 /// # Arguments
@@ -514,9 +1521,44 @@ pub fn create_parser(
             project_description: project_description.to_string(),
             flags: flags,
             arguments: arguments,
+            subcommands: Vec::new(),
+            help_width: None,
+            version: None,
+            author: None,
+            copyright: None,
+            usage_example: None,
         }
 }
 
+/// Creates a Parser meant to be attached to another Parser via
+/// [`Parser::add_subcommand`]. A subcommand is just a Parser, keyed
+/// by its own `project_title` when its parent delegates to it.
+///
+/// # Arguments
+/// - project_title: &str
+/// - project_description: &str
+/// - flags: Vec<Flag>
+/// - arguments: Vec<Argument>
+///
+/// # Returns
+/// Parser
+///
+/// # Examples
+/// ``` rust
+/// use argparser::*;
+///
+/// fn main() {
+///     let commit = create_subcommand("commit", "Record changes", Vec::new(), Vec::new());
+/// }
+/// ```
+pub fn create_subcommand(
+    project_title: &str,
+    project_description: &str,
+    flags: Vec<Flag>,
+    arguments: Vec<Argument> ) -> Parser {
+        create_parser(project_title, project_description, flags, arguments)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -535,8 +1577,7 @@ mod tests {
 
         let arg_parser = create_parser("Test Parser", "Tests arguments", flags, args);
         println!("{}", arg_parser.help());
-        // assert_eq!(arg_parser.flags[2].title, "c");
-        assert_eq!(true, false);
+        assert_eq!(arg_parser.flags[2].title, "c");
     }
 
     #[test]
@@ -554,4 +1595,342 @@ mod tests {
         let arg_parser = create_parser("Test Parser", "Tests arguments", flags, args);
         assert_eq!(arg_parser.flags[3].options.len(), 0);
     }
+
+    #[test]
+    fn add_subcommand_is_tracked_by_title() {
+        let mut arg_parser = create_parser("git", "A version control system", Vec::new(), Vec::new());
+        let commit = create_subcommand("commit", "Record changes", Vec::new(), Vec::new());
+        arg_parser.add_subcommand(commit);
+
+        assert_eq!(arg_parser.subcommands.len(), 1);
+        assert_eq!(arg_parser.subcommands[0].project_title, "commit");
+    }
+
+    #[test]
+    fn int_flag_rejects_non_numeric_value() {
+        let port_flag = create_flag_typed("p", "Port to listen on", vec!["port"], ValueType::Int);
+        assert!(port_flag.validate("8080").is_ok());
+        assert!(port_flag.validate("not-a-number").is_err());
+    }
+
+    #[test]
+    fn choice_flag_only_accepts_declared_choices() {
+        let mode_flag = create_flag_typed(
+            "m",
+            "Run mode",
+            vec!["mode"],
+            ValueType::Choice(vec!["fast".to_string(), "slow".to_string()]),
+        );
+        assert!(mode_flag.validate("fast").is_ok());
+        assert!(mode_flag.validate("medium").is_err());
+    }
+
+    #[test]
+    fn help_wraps_long_descriptions_to_the_pinned_width() {
+        let mut flags: Vec<Flag> = Vec::new();
+        flags.push(create_flag(
+            "a",
+            "This description is long enough that it must wrap onto more than one line",
+            vec![],
+        ));
+
+        let mut arg_parser = create_parser("Test Parser", "Tests arguments", flags, Vec::new());
+        arg_parser.help_width_override(40);
+
+        let help = arg_parser.help();
+        assert!(help.lines().all(|line| line.len() <= 40));
+        assert!(help.contains("    -a"));
+    }
+
+    #[test]
+    fn help_never_breaks_a_single_word_longer_than_the_available_width() {
+        let mut flags: Vec<Flag> = Vec::new();
+        flags.push(create_flag(
+            "a",
+            "这是一段没有空格可以用来换行的中文描述文字用来测试换行逻辑",
+            vec![],
+        ));
+
+        let mut arg_parser = create_parser("Test Parser", "Tests arguments", flags, Vec::new());
+        arg_parser.help_width_override(20);
+
+        let help = arg_parser.help();
+        let description_lines: Vec<&str> = help.lines()
+            .filter(|line| line.starts_with("    "))
+            .collect();
+        // The whole description is one unbreakable "word" (no whitespace),
+        // so it's left on a single overflowing line rather than broken.
+        assert_eq!(description_lines.len(), 1);
+    }
+
+    #[test]
+    fn long_flag_is_matched_by_name_and_short_form() {
+        let mut flags: Vec<Flag> = Vec::new();
+        flags.push(create_flag_long("p", "port", "Port to listen on", vec!["port"]));
+
+        let arg_parser = create_parser("Test Parser", "Tests arguments", flags, Vec::new());
+
+        let mut tokens = vec!["--port".to_string(), "8080".to_string()].into_iter();
+        let parsed = arg_parser.parse_from(&mut tokens).unwrap();
+        assert_eq!(parsed.get_str("p"), Some("8080"));
+
+        let mut tokens = vec!["-p".to_string(), "8080".to_string()].into_iter();
+        let parsed = arg_parser.parse_from(&mut tokens).unwrap();
+        assert_eq!(parsed.get_str("p"), Some("8080"));
+    }
+
+    #[test]
+    fn long_flag_accepts_inline_equals_value() {
+        let mut flags: Vec<Flag> = Vec::new();
+        flags.push(create_flag_long("p", "port", "Port to listen on", vec!["port"]));
+
+        let arg_parser = create_parser("Test Parser", "Tests arguments", flags, Vec::new());
+
+        let mut tokens = vec!["--port=8080".to_string()].into_iter();
+        let parsed = arg_parser.parse_from(&mut tokens).unwrap();
+        assert_eq!(parsed.get_str("p"), Some("8080"));
+    }
+
+    #[test]
+    fn short_flag_cluster_expands_to_individual_flags() {
+        let mut flags: Vec<Flag> = Vec::new();
+        flags.push(create_flag("a", "This is the a flag", vec![]));
+        flags.push(create_flag("b", "This is the b flag", vec![]));
+        flags.push(create_flag("c", "This is the c flag", vec![]));
+
+        let arg_parser = create_parser("Test Parser", "Tests arguments", flags, Vec::new());
+
+        let mut tokens = vec!["-abc".to_string()].into_iter();
+        let parsed = arg_parser.parse_from(&mut tokens).unwrap();
+        assert!(parsed.get_str("a").is_some());
+        assert!(parsed.get_str("b").is_some());
+        assert!(parsed.get_str("c").is_some());
+    }
+
+    #[test]
+    fn short_flag_cluster_rejects_a_flag_that_takes_a_value() {
+        let mut flags: Vec<Flag> = Vec::new();
+        flags.push(create_flag("a", "This is the a flag", vec![]));
+        flags.push(create_flag("b", "This is the b flag", vec!["some"]));
+
+        let arg_parser = create_parser("Test Parser", "Tests arguments", flags, Vec::new());
+
+        let mut tokens = vec!["-ab".to_string()].into_iter();
+        assert!(arg_parser.parse_from(&mut tokens).is_err());
+    }
+
+    #[test]
+    fn version_flag_returns_version_requested_error() {
+        let mut arg_parser = create_parser("Test Parser", "Tests arguments", Vec::new(), Vec::new());
+        arg_parser.set_version("1.2.3");
+        arg_parser.set_author("Jane Doe");
+
+        let mut tokens = vec!["--version".to_string()].into_iter();
+        let result = arg_parser.parse_from(&mut tokens);
+        match result {
+            Err(ParseError::VersionRequested(version)) => {
+                assert!(version.contains("Test Parser 1.2.3"));
+                assert!(version.contains("Jane Doe"));
+            }
+            _ => panic!("expected VersionRequested"),
+        }
+    }
+
+    #[test]
+    fn help_renders_a_concrete_usage_line() {
+        let mut flags: Vec<Flag> = Vec::new();
+        flags.push(create_flag("a", "This is the a flag", vec!["some"]));
+
+        let mut args: Vec<Argument> = Vec::new();
+        args.push(create_arg("foo", "This is the foo argument"));
+
+        let arg_parser = create_parser("Test Parser", "Tests arguments", flags, args);
+        let help = arg_parser.help();
+        assert!(help.contains("Usage: Test Parser [-a <some>] foo"));
+    }
+
+    #[test]
+    fn bash_completion_offers_flags_subcommands_and_choice_values() {
+        let mut flags: Vec<Flag> = Vec::new();
+        flags.push(create_flag_long("a", "all", "Show all", vec![]));
+        flags.push(create_flag_typed(
+            "m",
+            "Run mode",
+            vec!["mode"],
+            ValueType::Choice(vec!["fast".to_string(), "slow".to_string()]),
+        ));
+
+        let mut arg_parser = create_parser("myprog", "An example program", flags, Vec::new());
+        arg_parser.add_subcommand(create_subcommand("commit", "Record changes", Vec::new(), Vec::new()));
+
+        let script = arg_parser.generate_completion(Shell::Bash);
+        assert!(script.contains("-a --all"));
+        assert!(script.contains("commit"));
+        assert!(script.contains("fast slow"));
+    }
+
+    #[test]
+    fn bash_completion_escapes_dollar_and_backtick_in_choice_values() {
+        let mut flags: Vec<Flag> = Vec::new();
+        flags.push(create_flag_typed(
+            "m",
+            "Run mode",
+            vec!["mode"],
+            ValueType::Choice(vec!["$(touch /tmp/pwned)".to_string()]),
+        ));
+
+        let arg_parser = create_parser("myprog", "An example program", flags, Vec::new());
+
+        let script = arg_parser.generate_completion(Shell::Bash);
+        assert!(script.contains("\\$(touch"));
+        assert!(!script.contains("\"$(touch"));
+    }
+
+    #[test]
+    fn fish_completion_escapes_double_quotes_in_descriptions() {
+        let mut flags: Vec<Flag> = Vec::new();
+        flags.push(create_flag("a", "Use \"fast\" mode", vec![]));
+
+        let arg_parser = create_parser("myprog", "An example program", flags, Vec::new());
+
+        let script = arg_parser.generate_completion(Shell::Fish);
+        assert!(script.contains("-d \"Use \\\"fast\\\" mode\""));
+        for line in script.lines() {
+            assert_eq!(line.matches('"').count() % 2, 0, "unbalanced quotes in: {line}");
+        }
+    }
+
+    #[test]
+    fn zsh_completion_escapes_single_quotes_in_descriptions() {
+        let mut flags: Vec<Flag> = Vec::new();
+        flags.push(create_flag("a", "Use 'fast' mode", vec![]));
+
+        let arg_parser = create_parser("myprog", "An example program", flags, Vec::new());
+
+        let script = arg_parser.generate_completion(Shell::Zsh);
+        assert!(script.contains("Use '\\''fast'\\'' mode"));
+    }
+
+    #[test]
+    fn required_flag_errors_when_still_unset() {
+        let mut flags: Vec<Flag> = Vec::new();
+        let mut token_flag = create_flag("t", "Auth token", vec!["token"]);
+        token_flag.set_required(true);
+        flags.push(token_flag);
+
+        let arg_parser = create_parser("Test Parser", "Tests arguments", flags, Vec::new());
+
+        let mut tokens = Vec::<String>::new().into_iter();
+        match arg_parser.parse_from(&mut tokens) {
+            Err(ParseError::MissingRequiredFlag(flag)) => assert_eq!(flag, "t"),
+            _ => panic!("expected MissingRequiredFlag"),
+        }
+    }
+
+    #[test]
+    fn default_value_is_used_when_flag_is_absent() {
+        let mut flags: Vec<Flag> = Vec::new();
+        let mut port_flag = create_flag("p", "Port to listen on", vec!["port"]);
+        port_flag.set_default("8080");
+        flags.push(port_flag);
+
+        let arg_parser = create_parser("Test Parser", "Tests arguments", flags, Vec::new());
+
+        let mut tokens = Vec::<String>::new().into_iter();
+        let parsed = arg_parser.parse_from(&mut tokens).unwrap();
+        assert_eq!(parsed.get_str("p"), Some("8080"));
+    }
+
+    #[test]
+    fn env_var_fallback_takes_priority_over_default() {
+        let mut flags: Vec<Flag> = Vec::new();
+        let mut port_flag = create_flag("p", "Port to listen on", vec!["port"]);
+        port_flag.set_env("ARGPARSER_TEST_PORT");
+        port_flag.set_default("8080");
+        flags.push(port_flag);
+
+        let arg_parser = create_parser("Test Parser", "Tests arguments", flags, Vec::new());
+
+        std::env::set_var("ARGPARSER_TEST_PORT", "9090");
+        let mut tokens = Vec::<String>::new().into_iter();
+        let parsed = arg_parser.parse_from(&mut tokens).unwrap();
+        std::env::remove_var("ARGPARSER_TEST_PORT");
+
+        assert_eq!(parsed.get_str("p"), Some("9090"));
+    }
+
+    #[test]
+    fn required_top_level_flag_is_still_enforced_when_a_subcommand_is_used() {
+        let mut flags: Vec<Flag> = Vec::new();
+        let mut token_flag = create_flag("t", "Auth token", vec!["token"]);
+        token_flag.set_required(true);
+        flags.push(token_flag);
+
+        let mut arg_parser = create_parser("git", "A version control system", flags, Vec::new());
+        arg_parser.add_subcommand(create_subcommand("commit", "Record changes", Vec::new(), Vec::new()));
+
+        let mut tokens = vec!["commit".to_string()].into_iter();
+        match arg_parser.parse_from(&mut tokens) {
+            Err(ParseError::MissingRequiredFlag(flag)) => assert_eq!(flag, "t"),
+            _ => panic!("expected MissingRequiredFlag"),
+        }
+    }
+
+    #[test]
+    fn parent_flag_value_survives_into_parsed_args_when_a_subcommand_is_used() {
+        let mut flags: Vec<Flag> = Vec::new();
+        let mut token_flag = create_flag("t", "Auth token", vec!["token"]);
+        token_flag.set_required(true);
+        flags.push(token_flag);
+
+        let mut arg_parser = create_parser("git", "A version control system", flags, Vec::new());
+        arg_parser.add_subcommand(create_subcommand("commit", "Record changes", Vec::new(), Vec::new()));
+
+        let mut tokens = vec!["-t".to_string(), "sekret".to_string(), "commit".to_string()].into_iter();
+        let parsed = arg_parser.parse_from(&mut tokens).unwrap();
+        assert_eq!(parsed.get_str("t"), Some("sekret"));
+        assert_eq!(parsed.subcommand.as_deref(), Some("commit"));
+    }
+
+    #[test]
+    fn default_value_failing_validation_is_an_invalid_value_error() {
+        let mut flags: Vec<Flag> = Vec::new();
+        let mut port_flag = create_flag_typed("p", "Port to listen on", vec!["port"], ValueType::Int);
+        port_flag.set_default("not-a-number");
+        flags.push(port_flag);
+
+        let arg_parser = create_parser("Test Parser", "Tests arguments", flags, Vec::new());
+
+        let mut tokens = Vec::<String>::new().into_iter();
+        match arg_parser.parse_from(&mut tokens) {
+            Err(ParseError::InvalidValue { flag, value, .. }) => {
+                assert_eq!(flag, "p");
+                assert_eq!(value, "not-a-number");
+            }
+            _ => panic!("expected InvalidValue"),
+        }
+    }
+
+    #[test]
+    fn env_value_failing_validation_is_an_invalid_value_error() {
+        let mut flags: Vec<Flag> = Vec::new();
+        let mut port_flag = create_flag_typed("p", "Port to listen on", vec!["port"], ValueType::Int);
+        port_flag.set_env("ARGPARSER_TEST_BAD_PORT");
+        flags.push(port_flag);
+
+        let arg_parser = create_parser("Test Parser", "Tests arguments", flags, Vec::new());
+
+        std::env::set_var("ARGPARSER_TEST_BAD_PORT", "not-a-number");
+        let mut tokens = Vec::<String>::new().into_iter();
+        let result = arg_parser.parse_from(&mut tokens);
+        std::env::remove_var("ARGPARSER_TEST_BAD_PORT");
+
+        match result {
+            Err(ParseError::InvalidValue { flag, value, .. }) => {
+                assert_eq!(flag, "p");
+                assert_eq!(value, "not-a-number");
+            }
+            _ => panic!("expected InvalidValue"),
+        }
+    }
 }